@@ -0,0 +1,39 @@
+//! The `#[crux::test]` attribute.
+//!
+//! This macro itself does essentially nothing to the token stream: it
+//! leaves the annotated function's body untouched and only normalizes
+//! the attribute down to a bare marker (`#[crux_test]`) that survives
+//! into the compiled MIR. Unlike `#[test]` in the standard harness,
+//! crux-mir doesn't run the crate as a binary to discover its tests --
+//! it inspects the MIR of the whole crate directly -- so the actual
+//! work of turning "a function somewhere in the module tree has this
+//! marker" into "a fully-qualified, runnable test" happens on the
+//! driver side (see `Mir.FindTests` in crux-mir), which has the
+//! function's real module path available from its `DefId` without
+//! needing the unstable, run-time-only `module_path!()`.
+//!
+//! Any number of functions in any number of modules may carry this
+//! attribute; none of them needs to be named `crux_test` or live at the
+//! crate root anymore.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, ItemFn};
+
+#[proc_macro_attribute]
+pub fn test(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item_fn = parse_macro_input!(item as ItemFn);
+    let vis = &item_fn.vis;
+    let sig = &item_fn.sig;
+    let block = &item_fn.block;
+    let attrs = &item_fn.attrs;
+
+    let expanded = quote! {
+        #(#attrs)*
+        #[crux_test]
+        #vis #sig #block
+    };
+    expanded.into()
+}
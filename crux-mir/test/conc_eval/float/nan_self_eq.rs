@@ -0,0 +1,15 @@
+#![cfg_attr(not(with_main), no_std)]
+
+// A symbolic `x` that may be NaN must not be assumed equal to itself:
+// `x == x` is a genuine ordered comparison, not reflexivity.
+fn f(x: f64) -> bool {
+    x == x
+}
+
+const ARG: f64 = f64::NAN;
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", f(ARG));
+}
+#[cfg(not(with_main))] #[cfg_attr(crux, crux::test)] fn crux_test() -> bool { f(ARG) }
@@ -0,0 +1,13 @@
+#![cfg_attr(not(with_main), no_std)]
+
+fn f(x: f64, y: f64, z: f64) -> f64 {
+    x.sqrt().mul_add(y, z).floor() + (-x).abs().ceil()
+}
+
+const ARG: (f64, f64, f64) = (4.0, 2.0, 1.0);
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", f(ARG.0, ARG.1, ARG.2));
+}
+#[cfg(not(with_main))] #[cfg_attr(crux, crux::test)] fn crux_test() -> f64 { f(ARG.0, ARG.1, ARG.2) }
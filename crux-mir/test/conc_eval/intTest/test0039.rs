@@ -0,0 +1,19 @@
+#![cfg_attr(not(with_main), no_std)]
+
+// Exercises narrowing, widening, and same-width `IntToInt` casts,
+// including the `usize` pointer-width case.
+fn f(x: u32) -> u64 {
+    let a = x as u8 as u32;   // narrow then widen (unsigned)
+    let b = (x as i32) as i8 as i64; // narrow then widen (signed)
+    let c = x as usize as u64;
+    let d = (-1i8) as u64; // sign-extend across a widening cast
+    ((a as u64) + (b as u64) + (c as u64)).wrapping_add(d)
+}
+
+const ARG: u32 = 300;
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", f(ARG));
+}
+#[cfg(not(with_main))] #[cfg_attr(crux, crux::test)] fn crux_test() -> u64 { f(ARG) }
@@ -0,0 +1,20 @@
+#![cfg_attr(not(with_main), no_std)]
+#![feature(slice_ptr_len)]
+extern crate core;
+
+use core::ptr::NonNull;
+
+fn f(x: u8) -> u8 {
+    let mut xs = [x, x.wrapping_add(1), x.wrapping_add(2)];
+    let nn: NonNull<[u8]> = NonNull::from(&mut xs[..]);
+    let len = nn.len();
+    unsafe { *nn.as_non_null_ptr().as_ptr().add(len - 1) }
+}
+
+const ARG: u8 = 10;
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", f(ARG));
+}
+#[cfg(not(with_main))] #[cfg_attr(crux, crux::test)] fn crux_test() -> u8 { f(ARG) }
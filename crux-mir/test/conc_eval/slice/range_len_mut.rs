@@ -8,9 +8,7 @@ use core::ops::Range;
 fn f(x: u8) -> u8 {
     let mut xs = [x; 4];
     let ys = &mut xs[1..];
-    // usize -> u8 cast is unsupported, so we can't simply return `len as u8`.
-    assert!(ys.len() == 3);
-    1
+    ys.len() as u8
 }
 
 const ARG: u8 = 42;
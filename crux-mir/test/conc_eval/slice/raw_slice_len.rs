@@ -0,0 +1,21 @@
+#![cfg_attr(not(with_main), no_std)]
+#![feature(slice_ptr_len, slice_ptr_get)]
+
+// A `&mut [T]` reborrowed as a raw slice pointer keeps its length
+// metadata, so `.len()` and `get_unchecked` both work under symbolic
+// execution instead of hitting an unsupported-operation panic.
+fn f(x: u8) -> u8 {
+    let mut xs = [x; 4];
+    let ys: &mut [u8] = &mut xs[1..];
+    let ptr: *mut [u8] = ys as *mut [u8];
+    let len = ptr.len();
+    unsafe { *ptr.get_unchecked_mut(len - 1) }
+}
+
+const ARG: u8 = 7;
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", f(ARG));
+}
+#[cfg(not(with_main))] #[cfg_attr(crux, crux::test)] fn crux_test() -> u8 { f(ARG) }
@@ -0,0 +1,34 @@
+#![cfg_attr(not(with_main), no_std)]
+
+// Several `#[crux::test]` functions, nested in different modules, all
+// in one crate -- each should be reported under its fully-qualified
+// path (e.g. `checks::bounds::in_range`), not a bare function name.
+
+mod checks {
+    pub mod bounds {
+        #[cfg_attr(crux, crux::test)]
+        pub fn in_range() -> bool {
+            let x: u8 = 5;
+            x < 10
+        }
+    }
+
+    #[cfg_attr(crux, crux::test)]
+    pub fn nonzero() -> bool {
+        let x: u8 = 5;
+        x != 0
+    }
+}
+
+#[cfg_attr(crux, crux::test)]
+fn top_level() -> bool {
+    true
+}
+
+#[cfg(with_main)]
+pub fn main() {
+    println!(
+        "{:?}",
+        (checks::bounds::in_range(), checks::nonzero(), top_level())
+    );
+}
@@ -0,0 +1,25 @@
+// With `core::fmt`/print overrides in place, the same body -- including
+// the `println!` -- compiles both as a concrete `with_main` binary and
+// as a `#[crux::test]`: no more `#[cfg(with_main)]`/`#[cfg(not(with_main))]`
+// fork just to keep the non-`with_main` side from choking on formatting.
+// (`println!` is a `std` macro, so unlike the other tests in this
+// corpus this one isn't `no_std` -- the crux build links `std` the same
+// as a normal binary, it just runs under the `_print` override instead
+// of actually writing to stdout.)
+fn f(x: u8) -> u8 {
+    let y = x.wrapping_add(1);
+    println!("f({:?}) = {:?}", x, y);
+    y
+}
+
+const ARG: u8 = 41;
+
+#[cfg_attr(crux, crux::test)]
+fn crux_test() -> u8 {
+    f(ARG)
+}
+
+#[cfg(with_main)]
+pub fn main() {
+    println!("{:?}", crux_test());
+}